@@ -1,5 +1,17 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
 
+/// Re-exported so the `clsx!`/`clsx_write!`/`clsx_fmt!`/`clsx_dedup!` macros can
+/// reference `$crate::__ClsxString` instead of a bare `String`. A bare `String`
+/// in a macro body resolves at the macro's call site, not its definition site,
+/// so under `#![no_std]` it would otherwise require every caller to separately
+/// `use alloc::string::String;` before invoking these macros.
+#[doc(hidden)]
+pub use alloc::string::String as __ClsxString;
+
 /* -------------------------------------------------------------------------------------------------
  * Internal Macro: __clsx_count_args
  * -----------------------------------------------------------------------------------------------*/
@@ -29,7 +41,9 @@ macro_rules! __clsx_count_args {
 /// - Numeric types (converted to string)
 /// - `Option<T>` (appended if `Some`, ignored if `None`)
 /// - Slices/arrays of any `ClsxArg` type
-/// - `HashMap<String, bool>` (keys appended if value is `true`)
+/// - `HashMap<String, bool>` (keys appended, sorted, if value is `true`)
+/// - `BTreeMap<String, bool>` (keys appended in sorted order if value is `true`)
+/// - `indexmap::IndexMap<String, bool>` (keys appended in insertion order, behind the `indexmap` feature)
 /// - Tuples like `(bool, &str)` or `(bool, String)` (included only if boolean is `true`)
 /// - Closures returning something that implements `ClsxArg`
 pub trait ClsxArg {
@@ -38,6 +52,28 @@ pub trait ClsxArg {
     /// Implementors should insert a leading space if `out` is non-empty and a valid class
     /// is about to be appended.
     fn append_to(&self, out: &mut String);
+
+    /// Appends this argument's class(es) into any `fmt::Write` sink, for use by
+    /// [`clsx_fmt!`](crate::clsx_fmt) when streaming into a formatter/writer that
+    /// isn't a `String`.
+    ///
+    /// `needs_space` tracks whether a separating space is owed before the next
+    /// non-empty class; it starts `false` and is set to `true` after the first
+    /// class is written. The default implementation renders through [`Self::append_to`]
+    /// into a scratch `String`; implementors for which that would allocate
+    /// unnecessarily (e.g. `&str`, numeric types, containers) should override it.
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        let mut tmp = String::new();
+        self.append_to(&mut tmp);
+        if !tmp.is_empty() {
+            if *needs_space {
+                let _ = out.write_char(' ');
+            }
+            let _ = out.write_str(&tmp);
+            *needs_space = true;
+        }
+    }
 }
 
 /* -------------------------------------------------------------------------------------------------
@@ -54,6 +90,17 @@ fn push_with_space_if_needed(out: &mut String, val: &str) {
     }
 }
 
+#[inline]
+fn write_with_space_if_needed(out: &mut dyn core::fmt::Write, needs_space: &mut bool, val: &str) {
+    if !val.is_empty() {
+        if *needs_space {
+            let _ = out.write_char(' ');
+        }
+        let _ = out.write_str(val);
+        *needs_space = true;
+    }
+}
+
 /* -------------------------------------------------------------------------------------------------
  * Implementations for Strings & Str
  * -----------------------------------------------------------------------------------------------*/
@@ -63,6 +110,11 @@ impl ClsxArg for &str {
     fn append_to(&self, out: &mut String) {
         push_with_space_if_needed(out, self);
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        write_with_space_if_needed(out, needs_space, self);
+    }
 }
 
 impl ClsxArg for String {
@@ -70,6 +122,11 @@ impl ClsxArg for String {
     fn append_to(&self, out: &mut String) {
         push_with_space_if_needed(out, self);
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        write_with_space_if_needed(out, needs_space, self);
+    }
 }
 
 impl ClsxArg for &String {
@@ -77,6 +134,11 @@ impl ClsxArg for &String {
     fn append_to(&self, out: &mut String) {
         push_with_space_if_needed(out, self);
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        write_with_space_if_needed(out, needs_space, self);
+    }
 }
 
 impl ClsxArg for &&str {
@@ -84,6 +146,11 @@ impl ClsxArg for &&str {
     fn append_to(&self, out: &mut String) {
         push_with_space_if_needed(out, self);
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        write_with_space_if_needed(out, needs_space, self);
+    }
 }
 
 impl ClsxArg for &&&str {
@@ -91,6 +158,11 @@ impl ClsxArg for &&&str {
     fn append_to(&self, out: &mut String) {
         push_with_space_if_needed(out, self);
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        write_with_space_if_needed(out, needs_space, self);
+    }
 }
 
 /* -------------------------------------------------------------------------------------------------
@@ -103,6 +175,11 @@ impl ClsxArg for bool {
     fn append_to(&self, _out: &mut String) {
         // no-op
     }
+
+    #[inline]
+    fn append_to_writer(&self, _out: &mut dyn core::fmt::Write, _needs_space: &mut bool) {
+        // no-op
+    }
 }
 
 /* -------------------------------------------------------------------------------------------------
@@ -118,8 +195,17 @@ macro_rules! impl_number {
                     if !out.is_empty() {
                         out.push(' ');
                     }
-                    use std::fmt::Write;
+                    use core::fmt::Write;
+                    let _ = write!(out, "{}", self);
+                }
+
+                #[inline]
+                fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+                    if *needs_space {
+                        let _ = out.write_char(' ');
+                    }
                     let _ = write!(out, "{}", self);
+                    *needs_space = true;
                 }
             }
         )+
@@ -139,6 +225,13 @@ impl<T: ClsxArg> ClsxArg for Option<T> {
             val.append_to(out);
         }
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        if let Some(val) = self {
+            val.append_to_writer(out, needs_space);
+        }
+    }
 }
 
 impl<T: ClsxArg> ClsxArg for Vec<T> {
@@ -148,6 +241,13 @@ impl<T: ClsxArg> ClsxArg for Vec<T> {
             item.append_to(out);
         }
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        for item in self {
+            item.append_to_writer(out, needs_space);
+        }
+    }
 }
 
 impl<T: ClsxArg> ClsxArg for &[T] {
@@ -157,6 +257,13 @@ impl<T: ClsxArg> ClsxArg for &[T] {
             item.append_to(out);
         }
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        for item in *self {
+            item.append_to_writer(out, needs_space);
+        }
+    }
 }
 
 impl<T: ClsxArg, const N: usize> ClsxArg for [T; N] {
@@ -166,13 +273,69 @@ impl<T: ClsxArg, const N: usize> ClsxArg for [T; N] {
             item.append_to(out);
         }
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        for item in self {
+            item.append_to_writer(out, needs_space);
+        }
+    }
 }
 
 /* -------------------------------------------------------------------------------------------------
  * HashMap of (String -> bool)
  * -----------------------------------------------------------------------------------------------*/
 
+/// `HashMap` iteration order is unspecified, so the enabled keys are sorted
+/// lexicographically before being appended. This keeps `clsx!` output
+/// deterministic across runs, which matters for snapshot tests and cache
+/// keys. If you need insertion order instead, use an `IndexMap` (behind the
+/// `indexmap` feature) or a `BTreeMap` for sorted order without the
+/// collect-and-sort step.
+///
+/// Requires the `std` feature (on by default), since `HashMap` isn't
+/// available under `alloc` alone.
+#[cfg(feature = "std")]
 impl ClsxArg for HashMap<String, bool> {
+    #[inline]
+    fn append_to(&self, out: &mut String) {
+        let mut enabled: Vec<&str> = self
+            .iter()
+            .filter(|(class_name, flag)| **flag && !class_name.is_empty())
+            .map(|(class_name, _)| class_name.as_str())
+            .collect();
+        enabled.sort_unstable();
+        for class_name in enabled {
+            push_with_space_if_needed(out, class_name);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * BTreeMap of (String -> bool)
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Naturally ordered by key, so enabled classes are appended in sorted order
+/// with no extra collect-and-sort step.
+impl ClsxArg for BTreeMap<String, bool> {
+    #[inline]
+    fn append_to(&self, out: &mut String) {
+        for (class_name, flag) in self.iter() {
+            if *flag && !class_name.is_empty() {
+                push_with_space_if_needed(out, class_name);
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * IndexMap of (String -> bool) (feature = "indexmap")
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Preserves insertion order rather than sorting keys, for callers who want
+/// `clsx!` output to mirror the order classes were inserted into the map.
+#[cfg(feature = "indexmap")]
+impl<S: core::hash::BuildHasher> ClsxArg for indexmap::IndexMap<String, bool, S> {
     #[inline]
     fn append_to(&self, out: &mut String) {
         for (class_name, flag) in self.iter() {
@@ -194,6 +357,13 @@ impl ClsxArg for (bool, &str) {
             push_with_space_if_needed(out, self.1);
         }
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        if self.0 {
+            write_with_space_if_needed(out, needs_space, self.1);
+        }
+    }
 }
 
 impl ClsxArg for (bool, String) {
@@ -203,6 +373,13 @@ impl ClsxArg for (bool, String) {
             push_with_space_if_needed(out, &self.1);
         }
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        if self.0 {
+            write_with_space_if_needed(out, needs_space, &self.1);
+        }
+    }
 }
 
 /* -------------------------------------------------------------------------------------------------
@@ -218,6 +395,11 @@ where
     fn append_to(&self, out: &mut String) {
         (self)().append_to(out);
     }
+
+    #[inline]
+    fn append_to_writer(&self, out: &mut dyn core::fmt::Write, needs_space: &mut bool) {
+        (self)().append_to_writer(out, needs_space);
+    }
 }
 
 /* -------------------------------------------------------------------------------------------------
@@ -246,11 +428,11 @@ where
 #[macro_export]
 macro_rules! clsx {
     () => {
-        String::new()
+        $crate::__ClsxString::new()
     };
     ($($arg:expr),+ $(,)?) => {{
         const __COUNT: usize = $crate::__clsx_count_args!($($arg),*);
-        let mut out = String::with_capacity(__COUNT * 8);
+        let mut out = $crate::__ClsxString::with_capacity(__COUNT * 8);
         $(
             $crate::ClsxArg::append_to(&$arg, &mut out);
         )+
@@ -258,12 +440,123 @@ macro_rules! clsx {
     }};
 }
 
+/* -------------------------------------------------------------------------------------------------
+ * Macro: clsx_write!(...)
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Like [`clsx!`], but appends into a `&mut String` you already own instead
+/// of allocating a fresh one. A leading space is added only if the buffer is
+/// non-empty, so repeated calls into the same buffer compose correctly.
+/// Useful in hot render loops (server-side HTML rendering, templating
+/// engines) where the buffer's capacity can be reused across calls.
+///
+/// # Examples
+///
+/// ```rust
+/// use clsx::clsx_write;
+///
+/// let mut buf = String::with_capacity(64);
+/// clsx_write!(buf, "btn", (true, "btn-active"));
+/// assert_eq!(buf, "btn btn-active");
+/// ```
+#[macro_export]
+macro_rules! clsx_write {
+    ($buf:expr $(,)?) => {{
+        let _ = &mut $buf;
+    }};
+    ($buf:expr, $($arg:expr),+ $(,)?) => {{
+        $(
+            $crate::ClsxArg::append_to(&$arg, &mut $buf);
+        )+
+    }};
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Macro: clsx_fmt!(...)
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Like [`clsx!`], but streams classes directly into any `impl std::fmt::Write`
+/// sink (e.g. a formatter or HTML writer) instead of building an intermediate
+/// `String`. A separating space is written before each non-empty class after
+/// the first one written during this call.
+///
+/// # Examples
+///
+/// ```rust
+/// use clsx::clsx_fmt;
+/// use std::fmt::Write;
+///
+/// let mut buf = String::new();
+/// clsx_fmt!(buf, "btn", (true, "btn-active"));
+/// assert_eq!(buf, "btn btn-active");
+/// ```
+#[macro_export]
+macro_rules! clsx_fmt {
+    ($buf:expr $(,)?) => {{
+        let _ = &mut $buf;
+    }};
+    ($buf:expr, $($arg:expr),+ $(,)?) => {{
+        let mut __needs_space = false;
+        $(
+            $crate::ClsxArg::append_to_writer(&$arg, &mut $buf, &mut __needs_space);
+        )+
+    }};
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Deduplication: dedup_classes / clsx_dedup!(...)
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Removes duplicate whitespace-separated class tokens from `input`,
+/// keeping the first occurrence of each and preserving overall order. Class
+/// lists are typically short, so a linear scan against previously-seen
+/// tokens is used rather than hashing.
+pub fn dedup_classes(input: &str) -> String {
+    let mut seen: Vec<&str> = Vec::new();
+    let mut out = String::with_capacity(input.len());
+    for token in input.split_whitespace() {
+        if !seen.contains(&token) {
+            seen.push(token);
+            push_with_space_if_needed(&mut out, token);
+        }
+    }
+    out
+}
+
+/// Like [`clsx!`], but deduplicates repeated class tokens afterward, keeping
+/// the first occurrence of each and preserving order. This is opt-in:
+/// `clsx!` itself never deduplicates, to match JS `clsx` parity; reach for
+/// `clsx_dedup!` when composing from overlapping sources (a base array plus
+/// conditional overrides) is likely to repeat a token.
+///
+/// # Examples
+///
+/// ```rust
+/// use clsx::clsx_dedup;
+///
+/// let classes = clsx_dedup!("btn", ["btn", "p-4"]);
+/// assert_eq!(classes, "btn p-4");
+/// ```
+#[macro_export]
+macro_rules! clsx_dedup {
+    () => {
+        $crate::__ClsxString::new()
+    };
+    ($($arg:expr),+ $(,)?) => {{
+        let __joined = $crate::clsx!($($arg),+);
+        $crate::dedup_classes(&__joined)
+    }};
+}
+
 /* -------------------------------------------------------------------------------------------------
  * Tests
  * -----------------------------------------------------------------------------------------------*/
 
 #[cfg(test)]
 mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::string::{String, ToString};
+    #[cfg(feature = "std")]
     use std::collections::HashMap;
 
     #[test]
@@ -331,6 +624,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_clsx_with_hashmap() {
         let mut map = HashMap::new();
         map.insert("flex".to_string(), true);
@@ -339,6 +633,39 @@ mod tests {
         assert_eq!(result, "flex base");
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_clsx_with_hashmap_multi_key_deterministic() {
+        let mut map = HashMap::new();
+        map.insert("zebra".to_string(), true);
+        map.insert("apple".to_string(), true);
+        map.insert("mango".to_string(), true);
+        map.insert("skip".to_string(), false);
+        let result = clsx!(map, "base");
+        assert_eq!(result, "apple mango zebra base");
+    }
+
+    #[test]
+    fn test_clsx_with_btreemap() {
+        let mut map = BTreeMap::new();
+        map.insert("zebra".to_string(), true);
+        map.insert("apple".to_string(), true);
+        map.insert("skip".to_string(), false);
+        let result = clsx!(map, "base");
+        assert_eq!(result, "apple zebra base");
+    }
+
+    #[test]
+    #[cfg(feature = "indexmap")]
+    fn test_clsx_with_indexmap_preserves_insertion_order() {
+        let mut map = indexmap::IndexMap::new();
+        map.insert("zebra".to_string(), true);
+        map.insert("apple".to_string(), true);
+        map.insert("skip".to_string(), false);
+        let result = clsx!(map, "base");
+        assert_eq!(result, "zebra apple base");
+    }
+
     #[test]
     fn test_clsx_with_closures() {
         let result = clsx!(
@@ -378,6 +705,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_clsx_with_numerics() {
         let i = 10;
         let f = 3.14;
@@ -391,4 +719,52 @@ mod tests {
         let result = clsx!("hello", true, false, "world");
         assert_eq!(result, "hello world");
     }
+
+    #[test]
+    fn test_clsx_write_into_existing_buffer() {
+        let mut buf = String::from("existing");
+        clsx_write!(buf, "btn", (true, "btn-active"));
+        assert_eq!(buf, "existing btn btn-active");
+    }
+
+    #[test]
+    fn test_clsx_write_no_args() {
+        let mut buf = String::from("existing");
+        clsx_write!(buf);
+        assert_eq!(buf, "existing");
+    }
+
+    #[test]
+    fn test_clsx_fmt_into_string_writer() {
+        let mut buf = String::new();
+        clsx_fmt!(buf, "btn", (true, "btn-active"), false, "p-4");
+        assert_eq!(buf, "btn btn-active p-4");
+    }
+
+    #[test]
+    fn test_clsx_fmt_with_nested_structures() {
+        let mut buf = String::new();
+        clsx_fmt!(buf, ["foo", "bar"], (true, "extra"));
+        assert_eq!(buf, "foo bar extra");
+    }
+
+    #[test]
+    fn test_clsx_dedup_removes_repeated_tokens() {
+        let cond_arr = ["btn", "p-4"];
+        let result = clsx_dedup!("btn", cond_arr);
+        assert_eq!(result, "btn p-4");
+    }
+
+    #[test]
+    fn test_clsx_dedup_preserves_first_occurrence_order() {
+        let result = clsx_dedup!("b", "a", "b", "c", "a");
+        assert_eq!(result, "b a c");
+    }
+
+    #[test]
+    fn test_clsx_non_dedup_still_keeps_duplicates() {
+        let cond_arr = ["btn", "p-4"];
+        let result = clsx!("btn", cond_arr);
+        assert_eq!(result, "btn btn p-4");
+    }
 }
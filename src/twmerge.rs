@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+
+/* -------------------------------------------------------------------------------------------------
+ * Conflict classification
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Exact-match display utilities — these are mutually exclusive, unlike most
+/// utilities which are recognized by prefix.
+const DISPLAY_UTILITIES: &[&str] = &[
+    "block",
+    "inline-block",
+    "inline",
+    "flex",
+    "inline-flex",
+    "table",
+    "inline-table",
+    "grid",
+    "inline-grid",
+    "contents",
+    "hidden",
+];
+
+/// Tailwind's fixed font-size scale. Checked before the `text-` color prefix
+/// so `text-lg` and `text-red-500` land in different conflict groups.
+const FONT_SIZE_UTILITIES: &[&str] = &[
+    "text-xs", "text-sm", "text-base", "text-lg", "text-xl", "text-2xl", "text-3xl", "text-4xl",
+    "text-5xl", "text-6xl", "text-7xl", "text-8xl", "text-9xl",
+];
+
+/// Text-alignment keywords. Checked before the `text-` color prefix so
+/// `text-left` doesn't get lumped in with `text-red-500` under one color
+/// group.
+const TEXT_ALIGN_UTILITIES: &[&str] = &[
+    "text-left",
+    "text-center",
+    "text-right",
+    "text-justify",
+    "text-start",
+    "text-end",
+];
+
+/// A "slot" a box-model utility claims along one edge. Two utilities in the
+/// same category conflict (same variant chain) exactly when they claim at
+/// least one slot in common, so `p-4` (claiming all four) conflicts with
+/// `px-2` (left + right) and `pl-2` (left) alike, while `pl-2` and `pr-4`
+/// — disjoint slots — never conflict.
+const SLOT_TOP: &[&str] = &["top"];
+const SLOT_RIGHT: &[&str] = &["right"];
+const SLOT_BOTTOM: &[&str] = &["bottom"];
+const SLOT_LEFT: &[&str] = &["left"];
+const SLOT_X: &[&str] = &["left", "right"];
+const SLOT_Y: &[&str] = &["top", "bottom"];
+const SLOT_ALL: &[&str] = &["top", "right", "bottom", "left"];
+
+/// A single, category-wide slot for non-axis conflict groups (background
+/// color, text color, font size, display, text alignment, …), where any two
+/// classes in the group always conflict outright.
+const SLOT_ANY: &[&str] = &["_"];
+
+/// Classifies a base utility (variant prefixes already stripped) into a
+/// conflict category plus the slots it claims within that category. Two
+/// utilities under the same variant chain conflict when they share a
+/// category and at least one slot; the later one evicts the earlier. Returns
+/// `None` for utilities we don't recognize (including arbitrary properties
+/// like `[mask-type:luminance]`), which are never merged away. Arbitrary
+/// values behind a known prefix (e.g. `p-[3px]`, `bg-[#fff]`) are classified
+/// by that prefix like any other utility.
+fn classify_utility(utility: &str) -> Option<(&'static str, &'static [&'static str])> {
+    if DISPLAY_UTILITIES.contains(&utility) {
+        return Some(("display", SLOT_ANY));
+    }
+    if FONT_SIZE_UTILITIES.contains(&utility) {
+        return Some(("font-size", SLOT_ANY));
+    }
+    if TEXT_ALIGN_UTILITIES.contains(&utility) {
+        return Some(("text-align", SLOT_ANY));
+    }
+    if utility.starts_with("bg-") {
+        return Some(("background-color", SLOT_ANY));
+    }
+    if utility.starts_with("text-") {
+        return Some(("text-color", SLOT_ANY));
+    }
+    if let Some(slots) = classify_box_model_utility(utility, "p-", "px-", "py-", "pt-", "pr-", "pb-", "pl-") {
+        return Some(("padding", slots));
+    }
+    if let Some(slots) = classify_box_model_utility(utility, "m-", "mx-", "my-", "mt-", "mr-", "mb-", "ml-") {
+        return Some(("margin", slots));
+    }
+    None
+}
+
+/// Shared padding/margin prefix matching: both follow the same
+/// all/x/y/top/right/bottom/left prefix shape, just with `p`/`m` swapped.
+#[allow(clippy::too_many_arguments)]
+fn classify_box_model_utility(
+    utility: &str,
+    all: &str,
+    x: &str,
+    y: &str,
+    top: &str,
+    right: &str,
+    bottom: &str,
+    left: &str,
+) -> Option<&'static [&'static str]> {
+    if utility.starts_with(top) {
+        Some(SLOT_TOP)
+    } else if utility.starts_with(right) {
+        Some(SLOT_RIGHT)
+    } else if utility.starts_with(bottom) {
+        Some(SLOT_BOTTOM)
+    } else if utility.starts_with(left) {
+        Some(SLOT_LEFT)
+    } else if utility.starts_with(x) {
+        Some(SLOT_X)
+    } else if utility.starts_with(y) {
+        Some(SLOT_Y)
+    } else if utility.starts_with(all) {
+        Some(SLOT_ALL)
+    } else {
+        None
+    }
+}
+
+/// Splits a class like `hover:md:bg-red-500` into its variant prefix chain
+/// (`hover:md:`) and base utility (`bg-red-500`). Classes with no `:` have
+/// an empty variant prefix.
+fn split_variant(class: &str) -> (&str, &str) {
+    match class.rfind(':') {
+        Some(idx) => (&class[..=idx], &class[idx + 1..]),
+        None => ("", class),
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * tw_merge
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Resolves conflicting Tailwind utility classes in a space-separated class
+/// string so that, within the same variant chain (e.g. `hover:md:`), the
+/// last class claiming a given slot (an edge for padding/margin, or the
+/// whole category for background color, text color, font size, display,
+/// text alignment, …) wins. Overall left-to-right order is otherwise
+/// preserved. Unknown classes and arbitrary properties (e.g. `[mask-type:luminance]`)
+/// are passed through untouched and never merged; arbitrary values behind a
+/// known prefix (e.g. `p-[3px]`) conflict like any other class with that prefix.
+///
+/// # Examples
+///
+/// ```rust
+/// use clsx::tw_merge;
+///
+/// assert_eq!(tw_merge("p-2 p-4"), "p-4");
+/// assert_eq!(tw_merge("pl-2 pr-4"), "pl-2 pr-4");
+/// assert_eq!(tw_merge("px-2 p-4"), "p-4");
+/// assert_eq!(tw_merge("hover:bg-red-500 hover:bg-blue-500"), "hover:bg-blue-500");
+/// ```
+pub fn tw_merge(input: &str) -> String {
+    let mut classes: Vec<Option<&str>> = Vec::new();
+    let mut last_index: HashMap<(&str, &'static str, &'static str), usize> = HashMap::new();
+
+    for token in input.split_whitespace() {
+        let (variant, utility) = split_variant(token);
+        match classify_utility(utility) {
+            Some((category, slots)) => {
+                let mut evict: Vec<usize> = Vec::new();
+                for slot in slots {
+                    if let Some(&old_idx) = last_index.get(&(variant, category, *slot)) {
+                        if !evict.contains(&old_idx) {
+                            evict.push(old_idx);
+                        }
+                    }
+                }
+                for old_idx in evict {
+                    classes[old_idx] = None;
+                }
+                let new_idx = classes.len();
+                classes.push(Some(token));
+                for slot in slots {
+                    last_index.insert((variant, category, *slot), new_idx);
+                }
+            }
+            None => classes.push(Some(token)),
+        }
+    }
+
+    classes
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Macro: twmerge!(...)
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Like [`clsx!`](crate::clsx), but resolves conflicting Tailwind utility
+/// classes afterward so the last conflicting class wins, mirroring the JS
+/// [`tailwind-merge`](https://github.com/dcastil/tailwind-merge) library.
+///
+/// # Examples
+///
+/// ```rust
+/// use clsx::twmerge;
+///
+/// let classes = twmerge!("p-2", "p-4");
+/// assert_eq!(classes, "p-4");
+/// ```
+#[macro_export]
+macro_rules! twmerge {
+    () => {
+        String::new()
+    };
+    ($($arg:expr),+ $(,)?) => {{
+        let __joined = $crate::clsx!($($arg),+);
+        $crate::tw_merge(&__joined)
+    }};
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Tests
+ * -----------------------------------------------------------------------------------------------*/
+
+#[cfg(test)]
+mod tests {
+    use super::tw_merge;
+
+    #[test]
+    fn test_tw_merge_padding_conflict() {
+        assert_eq!(tw_merge("p-2 p-4"), "p-4");
+    }
+
+    #[test]
+    fn test_tw_merge_different_groups_no_conflict() {
+        assert_eq!(tw_merge("px-2 m-4"), "px-2 m-4");
+    }
+
+    #[test]
+    fn test_tw_merge_padding_prefix_variants_conflict() {
+        assert_eq!(tw_merge("px-2 p-4"), "p-4");
+    }
+
+    #[test]
+    fn test_tw_merge_padding_disjoint_sides_no_conflict() {
+        assert_eq!(tw_merge("pl-2 pr-4"), "pl-2 pr-4");
+    }
+
+    #[test]
+    fn test_tw_merge_padding_disjoint_top_bottom_no_conflict() {
+        assert_eq!(tw_merge("pt-2 pb-4"), "pt-2 pb-4");
+    }
+
+    #[test]
+    fn test_tw_merge_padding_x_evicts_only_shared_sides() {
+        assert_eq!(tw_merge("pl-2 px-4"), "px-4");
+        assert_eq!(tw_merge("pl-2 px-4 pt-1"), "px-4 pt-1");
+    }
+
+    #[test]
+    fn test_tw_merge_margin_disjoint_sides_no_conflict() {
+        assert_eq!(tw_merge("ml-2 mr-4"), "ml-2 mr-4");
+    }
+
+    #[test]
+    fn test_tw_merge_margin_all_evicted_by_single_side() {
+        assert_eq!(tw_merge("m-2 mt-4"), "mt-4");
+    }
+
+    #[test]
+    fn test_tw_merge_text_color_vs_font_size() {
+        assert_eq!(tw_merge("text-red-500 text-lg"), "text-red-500 text-lg");
+    }
+
+    #[test]
+    fn test_tw_merge_text_align_vs_text_color() {
+        assert_eq!(tw_merge("text-left text-red-500"), "text-left text-red-500");
+    }
+
+    #[test]
+    fn test_tw_merge_text_align_conflict() {
+        assert_eq!(tw_merge("text-left text-center"), "text-center");
+    }
+
+    #[test]
+    fn test_tw_merge_text_color_conflict() {
+        assert_eq!(tw_merge("text-red-500 text-blue-500"), "text-blue-500");
+    }
+
+    #[test]
+    fn test_tw_merge_variant_scoped() {
+        assert_eq!(
+            tw_merge("hover:bg-red-500 hover:bg-blue-500"),
+            "hover:bg-blue-500"
+        );
+    }
+
+    #[test]
+    fn test_tw_merge_variant_does_not_conflict_with_base() {
+        assert_eq!(tw_merge("bg-red-500 hover:bg-blue-500"), "bg-red-500 hover:bg-blue-500");
+    }
+
+    #[test]
+    fn test_tw_merge_display_conflict() {
+        assert_eq!(tw_merge("block flex grid"), "grid");
+    }
+
+    #[test]
+    fn test_tw_merge_arbitrary_values_untouched() {
+        assert_eq!(
+            tw_merge("[mask-type:luminance] [mask-type:alpha]"),
+            "[mask-type:luminance] [mask-type:alpha]"
+        );
+    }
+
+    #[test]
+    fn test_twmerge_macro() {
+        let classes = twmerge!("p-2", "p-4", "text-sm");
+        assert_eq!(classes, "p-4 text-sm");
+    }
+}